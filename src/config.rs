@@ -9,11 +9,16 @@ pub struct SiteConfig {
     pub description: Option<String>,
     pub baseurl: Option<String>,
     pub url: Option<String>,
-    pub languages: Option<Vec<String>>,
+    pub languages: Option<Vec<LanguageConfig>>,
     pub default_lang: Option<String>,
     pub exclude_from_localization: Option<Vec<String>>,
     pub markdown: Option<String>,
     pub highlighter: Option<String>,
+    /// Theme for the syntect-based code highlighter (a `ThemeSet` theme
+    /// name, or `"css"` for class-based output). Deliberately separate from
+    /// `highlighter`, which is a carried-over Jekyll key holding an *engine*
+    /// name (`rouge`, `pygments`) and isn't a valid syntect theme.
+    pub syntect_theme: Option<String>,
     pub permalink: Option<String>,
     pub plugins: Option<Vec<String>>,
     pub collections: Option<HashMap<String, CollectionConfig>>,
@@ -22,6 +27,70 @@ pub struct SiteConfig {
     pub kramdown: Option<KramdownConfig>,
     pub version: Option<String>,
     pub rust_version: Option<String>,
+    pub sass: Option<SassConfig>,
+    pub taxonomies: Option<Vec<TaxonomyConfig>>,
+    pub minify_html: Option<bool>,
+}
+
+/// A `languages` entry: either a bare code (`en`) or a table opting that
+/// language into per-language features like feeds and search. Keeping the
+/// bare-code form valid means existing `_config.yml` files don't need to
+/// change just to add a feed for one language.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LanguageConfig {
+    Code(String),
+    Full(LanguageEntry),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageEntry {
+    pub code: String,
+    /// `None` means "use the default-language fallback" rather than "off",
+    /// so a table entry for the default language still gets a feed unless
+    /// it explicitly sets `rss: false`.
+    pub rss: Option<bool>,
+    #[serde(default)]
+    pub search: bool,
+}
+
+impl LanguageConfig {
+    pub fn code(&self) -> &str {
+        match self {
+            LanguageConfig::Code(code) => code,
+            LanguageConfig::Full(entry) => &entry.code,
+        }
+    }
+
+    /// Whether this language gets a feed: an explicit `rss` flag wins,
+    /// otherwise the default language keeps the baseline's always-on feed
+    /// so a bare `languages: [en, pt, es]` config isn't silently left
+    /// feed-less.
+    pub fn rss(&self, default_lang: &str) -> bool {
+        match self {
+            LanguageConfig::Code(code) => code == default_lang,
+            LanguageConfig::Full(entry) => entry.rss.unwrap_or(entry.code == default_lang),
+        }
+    }
+
+    pub fn search(&self) -> bool {
+        match self {
+            LanguageConfig::Code(_) => false,
+            LanguageConfig::Full(entry) => entry.search,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxonomyConfig {
+    pub name: String,
+    pub paginate_by: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SassConfig {
+    pub enabled: Option<bool>,
+    pub style: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,10 +128,42 @@ impl SiteConfig {
 
     pub fn get_languages(&self) -> Vec<String> {
         self.languages
-            .clone()
+            .as_ref()
+            .map(|langs| langs.iter().map(|l| l.code().to_string()).collect())
             .unwrap_or_else(|| vec!["en".to_string()])
     }
 
+    /// Languages that get a feed: the default language always does unless a
+    /// table entry opts it out, and other languages opt in with `rss: true`.
+    pub fn rss_languages(&self) -> Vec<String> {
+        let default_lang = self.get_default_lang();
+        self.languages
+            .as_ref()
+            .map(|langs| {
+                langs
+                    .iter()
+                    .filter(|l| l.rss(&default_lang))
+                    .map(|l| l.code().to_string())
+                    .collect()
+            })
+            .unwrap_or_else(|| vec![default_lang])
+    }
+
+    /// Languages opted into search index generation via `search: true` in
+    /// their `languages` entry.
+    pub fn search_languages(&self) -> Vec<String> {
+        self.languages
+            .as_ref()
+            .map(|langs| {
+                langs
+                    .iter()
+                    .filter(|l| l.search())
+                    .map(|l| l.code().to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn get_default_lang(&self) -> String {
         self.default_lang
             .clone()
@@ -79,4 +180,27 @@ impl SiteConfig {
     pub fn get_collection_config(&self, name: &str) -> Option<&CollectionConfig> {
         self.collections.as_ref()?.get(name)
     }
+
+    pub fn sass_enabled(&self) -> bool {
+        self.sass
+            .as_ref()
+            .and_then(|s| s.enabled)
+            .unwrap_or(true)
+    }
+
+    pub fn sass_compressed(&self) -> bool {
+        self.sass
+            .as_ref()
+            .and_then(|s| s.style.as_deref())
+            .map(|style| style != "expanded")
+            .unwrap_or(true)
+    }
+
+    pub fn taxonomies(&self) -> &[TaxonomyConfig] {
+        self.taxonomies.as_deref().unwrap_or(&[])
+    }
+
+    pub fn minify_html(&self) -> bool {
+        self.minify_html.unwrap_or(false)
+    }
 }
\ No newline at end of file
@@ -1,12 +1,14 @@
 use anyhow::Result;
 use gray_matter::{Matter, Pod};
 use gray_matter::engine::YAML;
-use pulldown_cmark::{html, Options, Parser};
+use pulldown_cmark::{html, CodeBlockKind, CowStr, Event, Options, Parser, Tag};
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use crate::highlight::SyntaxHighlighter;
+
 fn pod_to_yaml_value(pod: Pod) -> Value {
     match pod {
         Pod::String(s) => Value::String(s),
@@ -25,6 +27,37 @@ fn pod_to_yaml_value(pod: Pod) -> Value {
     }
 }
 
+/// Collects each fenced code block's source and language token, replacing it
+/// with a single highlighted `Event::Html`, so the rest of the event stream
+/// passes through untouched.
+fn highlight_code_blocks<'a>(parser: Parser<'a>, highlighter: &SyntaxHighlighter) -> Vec<Event<'a>> {
+    let mut events = Vec::new();
+    let mut in_code_block = false;
+    let mut lang = String::new();
+    let mut code = String::new();
+
+    for event in parser {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(token))) => {
+                in_code_block = true;
+                lang = token.to_string();
+                code.clear();
+            }
+            Event::Text(text) if in_code_block => {
+                code.push_str(&text);
+            }
+            Event::End(Tag::CodeBlock(_)) if in_code_block => {
+                in_code_block = false;
+                let highlighted = highlighter.highlight(&code, &lang);
+                events.push(Event::Html(CowStr::from(highlighted)));
+            }
+            other => events.push(other),
+        }
+    }
+
+    events
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FrontMatter {
     pub title: Option<String>,
@@ -49,10 +82,42 @@ pub struct ContentFile {
     pub html_content: String,
     pub collection: Option<String>,
     pub language: String,
+    /// File stem cut at its first `.` when the rest is a configured language
+    /// code, e.g. `variables` for both `variables.md` and `variables.fr.md`.
+    pub name: String,
+    /// Collection joined with `name` (or front matter's `translation_key`
+    /// when set): the key shared by every language variant of a page,
+    /// independent of which directory convention or slug each variant uses,
+    /// so translations still group across `_lessons_pt/`-style directories
+    /// and differently-slugged pages. Used to build `translations`.
+    pub canonical: PathBuf,
+    /// Every language variant of this page (this page included), keyed by
+    /// the `canonical` group.
+    /// Populated after all content files are collected; empty until then.
+    #[serde(skip)]
+    pub translations: Vec<Translation>,
+    /// The site's default language, carried alongside `language` so output
+    /// paths can be built without threading `SiteConfig` through every call.
+    pub default_lang: String,
+}
+
+/// One language variant of a page, as exposed to templates for language
+/// switching.
+#[derive(Debug, Clone, Serialize)]
+pub struct Translation {
+    pub lang: String,
+    pub permalink: String,
+    pub title: String,
 }
 
 impl ContentFile {
-    pub fn from_path(path: &Path, source_root: &Path) -> Result<Self> {
+    pub fn from_path(
+        path: &Path,
+        source_root: &Path,
+        languages: &[String],
+        default_lang: &str,
+        highlighter: &SyntaxHighlighter,
+    ) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
         let matter = Matter::<YAML>::new();
         let result = matter.parse(&content);
@@ -121,7 +186,9 @@ impl ContentFile {
             }
         };
 
-        // Convert markdown to HTML
+        // Convert markdown to HTML, highlighting fenced code blocks with
+        // syntect before `push_html` consumes the event stream so tables,
+        // footnotes, and tasklists render as usual.
         let mut options = Options::empty();
         options.insert(Options::ENABLE_STRIKETHROUGH);
         options.insert(Options::ENABLE_FOOTNOTES);
@@ -129,12 +196,46 @@ impl ContentFile {
         options.insert(Options::ENABLE_TASKLISTS);
 
         let parser = Parser::new_ext(&result.content, options);
+        let events = highlight_code_blocks(parser, highlighter);
         let mut html_output = String::new();
-        html::push_html(&mut html_output, parser);
+        html::push_html(&mut html_output, events.into_iter());
 
         // Determine collection and language from path
         let relative_path = path.strip_prefix(source_root)?.to_path_buf();
-        let (collection, language) = Self::extract_collection_and_language(&relative_path);
+        let (collection, dir_language) =
+            Self::extract_collection_and_language(&relative_path, languages, default_lang);
+
+        // Zola-style file_info: a filename can also carry its language as a
+        // `name.lang.md` suffix, which takes precedence over the directory.
+        // A second dot-segment that isn't a configured language code (e.g.
+        // `foo.bar.md`) isn't a language suffix at all, so the full stem is
+        // kept and the directory-derived language applies instead.
+        let raw_stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("index")
+            .to_string();
+
+        let (name, language) = match raw_stem.split_once('.') {
+            Some((base, suffix)) if languages.iter().any(|l| l == suffix) => {
+                (base.to_string(), suffix.to_string())
+            }
+            _ => (raw_stem, dir_language),
+        };
+
+        // Group by collection + name rather than the literal parent
+        // directory, so `_lessons/variables.md` and `_lessons_pt/variables.md`
+        // (both collection `lessons`, language carried separately) canonicalize
+        // the same instead of being kept apart by their differing directory
+        // names. A page whose translation uses a different slug entirely
+        // (`hello-world` / `ola-mundo`) can set `translation_key` in front
+        // matter to join that group explicitly, since name-matching alone
+        // can't find it.
+        let group_key = match front_matter.extra.get("translation_key") {
+            Some(Value::String(key)) => key.clone(),
+            _ => name.clone(),
+        };
+        let canonical = PathBuf::from(format!("{}/{}", collection.as_deref().unwrap_or(""), group_key));
 
         Ok(ContentFile {
             path: path.to_path_buf(),
@@ -144,169 +245,111 @@ impl ContentFile {
             html_content: html_output,
             collection,
             language,
+            name,
+            canonical,
+            translations: Vec::new(),
+            default_lang: default_lang.to_string(),
         })
     }
 
-    fn extract_collection_and_language(path: &Path) -> (Option<String>, String) {
+    /// Detects a collection and language from `path`'s directory structure
+    /// for any configured language, not just the two that used to be
+    /// hardcoded: `_lessons_<code>/` (or bare `_lessons/` for the default
+    /// language) is the `lessons` collection, and a leading `<code>/`
+    /// segment covers every other language-specific page.
+    fn extract_collection_and_language(path: &Path, languages: &[String], default_lang: &str) -> (Option<String>, String) {
         let path_str = path.to_string_lossy();
-        
-        // Extract collection from path (e.g., _lessons, _lessons_pt, _lessons_es)
-        if path_str.starts_with("_lessons_pt") {
-            (Some("lessons".to_string()), "pt".to_string())
-        } else if path_str.starts_with("_lessons_es") {
-            (Some("lessons".to_string()), "es".to_string())
-        } else if path_str.starts_with("_lessons") {
-            (Some("lessons".to_string()), "en".to_string())
-        } else if path_str.starts_with("pt/") {
-            (None, "pt".to_string())
-        } else if path_str.starts_with("es/") {
-            (None, "es".to_string())
+
+        if let Some(rest) = path_str.strip_prefix("_lessons_") {
+            let code = rest.split('/').next().unwrap_or("");
+            if languages.iter().any(|l| l == code) {
+                return (Some("lessons".to_string()), code.to_string());
+            }
+        }
+        if path_str.starts_with("_lessons") {
+            return (Some("lessons".to_string()), default_lang.to_string());
+        }
+
+        let first_segment = path_str.split('/').next().unwrap_or("");
+        if first_segment != default_lang && languages.iter().any(|l| l == first_segment) {
+            return (None, first_segment.to_string());
+        }
+
+        (None, default_lang.to_string())
+    }
+
+    /// The `/` prefix for this page's language: empty for the default
+    /// language (which lives at the site root), `{code}/` for every other
+    /// configured language. The single source of truth for language-prefixed
+    /// paths, replacing what used to be per-language `match` branches in
+    /// every path-building method.
+    fn lang_path_prefix(&self) -> String {
+        if self.language == self.default_lang {
+            String::new()
         } else {
-            (None, "en".to_string())
+            format!("{}/", self.language)
         }
     }
 
     pub fn get_output_path(&self, _base_url: &str) -> String {
-        let stem = self.path.file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("index");
+        let prefix = self.lang_path_prefix();
+
+        // `index.md` names a directory's own page, not a sibling named
+        // "index" — collapse it to its collection's root (`_lessons/index.md`
+        // -> `/lessons/`), or to the language root when it has no collection,
+        // instead of nesting an extra `/index/` segment. Only the collection
+        // is consulted, so a `lessons/index.md` outside `_lessons/` also maps
+        // to the language root.
+        if self.name == "index" {
+            return match &self.collection {
+                Some(collection) => format!("/{}{}/", prefix, collection),
+                None => format!("/{}", prefix),
+            };
+        }
 
         if let Some(collection) = &self.collection {
-            match self.language.as_str() {
-                "pt" => format!("/pt/{}/{}/", collection, stem),
-                "es" => format!("/es/{}/{}/", collection, stem),
-                _ => format!("/{}/{}/", collection, stem),
-            }
+            format!("/{}{}/{}/", prefix, collection, self.name)
         } else {
-            match self.language.as_str() {
-                "pt" => format!("/pt/{}/", stem),
-                "es" => format!("/es/{}/", stem),
-                _ => format!("/{}/", stem),
-            }
+            format!("/{}{}/", prefix, self.name)
         }
     }
 
     pub fn get_file_path(&self) -> PathBuf {
         let mut path = PathBuf::new();
-        
+        let prefix = self.lang_path_prefix();
+
+        if !prefix.is_empty() {
+            path.push(prefix.trim_end_matches('/'));
+        }
         if let Some(collection) = &self.collection {
-            match self.language.as_str() {
-                "pt" => path.push(format!("pt/{}", collection)),
-                "es" => path.push(format!("es/{}", collection)),
-                _ => path.push(collection),
-            }
-        } else {
-            match self.language.as_str() {
-                "pt" => path.push("pt"),
-                "es" => path.push("es"),
-                _ => {}
-            }
+            path.push(collection);
         }
 
-        let stem = self.path.file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("index");
-        
-        path.push(stem);
+        if self.name != "index" {
+            path.push(&self.name);
+        }
         path.push("index.html");
         path
     }
 
-    /// Get the equivalent URL for this page in other languages
-    pub fn get_language_urls(&self) -> std::collections::HashMap<String, String> {
-        let mut urls = std::collections::HashMap::new();
-        let stem = self.path.file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("index");
-
-        // Map common lesson names across languages
-        let lesson_map = get_lesson_translation_map();
-        
-        if let Some(collection) = &self.collection {
-            // For lessons, try to find equivalent content
-            let en_stem = match self.language.as_str() {
-                "pt" | "es" => lesson_map.get(stem).unwrap_or(&stem.to_string()).clone(),
-                _ => stem.to_string(),
-            };
-            
-            // Generate URLs for each language
-            urls.insert("en".to_string(), format!("/{}/{}/", collection, en_stem));
-            
-            // For Portuguese, find PT equivalent or fallback to main page
-            let pt_lesson = match en_stem.as_str() {
-                "hello-world" => "ola-mundo",
-                "installation" => "instalacao",
-                "variables" => "variaveis", 
-                "data-types" => "tipos-de-dados",
-                "cargo" => "cargo",
-                _ => {
-                    // Try reverse lookup from lesson_map
-                    lesson_map.iter()
-                        .find(|(_, v)| *v == &en_stem)
-                        .map(|(k, _)| k.as_str())
-                        .unwrap_or("index")
-                }
-            };
-            
-            if pt_lesson != "index" {
-                urls.insert("pt".to_string(), format!("/pt/{}/{}/", collection, pt_lesson));
-            } else {
-                urls.insert("pt".to_string(), "/pt/".to_string());
-            }
-            
-            // For Spanish, find ES equivalent or fallback to main page
-            let es_lesson = match en_stem.as_str() {
-                "hello-world" => "hola-mundo",
-                "installation" => "instalacion", 
-                "variables" => "variables",
-                "cargo" => "cargo",
-                _ => {
-                    // Try reverse lookup from lesson_map
-                    lesson_map.iter()
-                        .find(|(_, v)| *v == &en_stem)
-                        .map(|(k, _)| k.as_str())
-                        .unwrap_or("index")
-                }
-            };
-            
-            if es_lesson != "index" {
-                urls.insert("es".to_string(), format!("/es/{}/{}/", collection, es_lesson));
-            } else {
-                urls.insert("es".to_string(), "/es/".to_string());
-            }
-        } else {
-            // For regular pages
-            if stem == "index" {
-                urls.insert("en".to_string(), "/".to_string());
-                urls.insert("pt".to_string(), "/pt/".to_string());
-                urls.insert("es".to_string(), "/es/".to_string());
-            } else {
-                urls.insert("en".to_string(), format!("/{}/", stem));
-                urls.insert("pt".to_string(), format!("/pt/{}/", stem));
-                urls.insert("es".to_string(), format!("/es/{}/", stem));
-            }
+    /// Optional front-matter `date`, used to order and stamp generated
+    /// sitemap/feed entries. Lives in `extra` since `FrontMatter` doesn't
+    /// promote it to a named field.
+    pub fn date(&self) -> Option<String> {
+        match self.front_matter.extra.get("date") {
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(other) => serde_yaml::to_string(other).ok().map(|s| s.trim().to_string()),
+            None => None,
         }
-        
-        urls
     }
-}
 
-/// Map lesson names between languages  
-fn get_lesson_translation_map() -> std::collections::HashMap<String, String> {
-    let mut map = std::collections::HashMap::new();
-    
-    // Portuguese to English mappings
-    map.insert("ola-mundo".to_string(), "hello-world".to_string());
-    map.insert("instalacao".to_string(), "installation".to_string());
-    map.insert("variaveis".to_string(), "variables".to_string());
-    map.insert("tipos-de-dados".to_string(), "data-types".to_string());
-    map.insert("cargo".to_string(), "cargo".to_string());
-    
-    // Spanish to English mappings  
-    map.insert("hola-mundo".to_string(), "hello-world".to_string());
-    map.insert("instalacion".to_string(), "installation".to_string());
-    map.insert("variables".to_string(), "variables".to_string());
-    map.insert("cargo".to_string(), "cargo".to_string());
-    
-    map
+    /// Get the equivalent URL for this page in other languages, built
+    /// directly from `translations` (populated by grouping every content
+    /// file by `canonical` during the build) instead of guessing slugs.
+    pub fn get_language_urls(&self) -> std::collections::HashMap<String, String> {
+        self.translations
+            .iter()
+            .map(|t| (t.lang.clone(), t.permalink.clone()))
+            .collect()
+    }
 }
\ No newline at end of file
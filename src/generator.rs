@@ -1,35 +1,53 @@
 use anyhow::Result;
+use rayon::prelude::*;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 use crate::config::SiteConfig;
 use crate::content::ContentFile;
+use crate::highlight::SyntaxHighlighter;
 use crate::templates::TemplateEngine;
 use crate::utils::{copy_dir_recursive, ensure_dir_exists, is_markdown_file, should_exclude};
 
+#[derive(Clone)]
 pub struct SiteGenerator {
     source_dir: PathBuf,
     output_dir: PathBuf,
     config: SiteConfig,
     template_engine: TemplateEngine,
+    highlighter: SyntaxHighlighter,
 }
 
 impl SiteGenerator {
     pub fn new(source_dir: &str, output_dir: &str, config: SiteConfig) -> Result<Self> {
         let source_path = PathBuf::from(source_dir);
         let output_path = PathBuf::from(output_dir);
-        
+
         let template_engine = TemplateEngine::new(&source_path)?;
+        let highlighter = SyntaxHighlighter::new(config.syntect_theme.as_deref())?;
 
         Ok(SiteGenerator {
             source_dir: source_path,
             output_dir: output_path,
             config,
             template_engine,
+            highlighter,
         })
     }
 
+    pub fn source_dir(&self) -> &Path {
+        &self.source_dir
+    }
+
+    pub fn output_dir(&self) -> &Path {
+        &self.output_dir
+    }
+
+    pub fn config(&self) -> &SiteConfig {
+        &self.config
+    }
+
     pub async fn build(&self) -> Result<()> {
         println!("🧹 Cleaning output directory...");
         if self.output_dir.exists() {
@@ -38,12 +56,13 @@ impl SiteGenerator {
         fs::create_dir_all(&self.output_dir)?;
 
         println!("📁 Processing content files...");
-        let content_files = self.collect_content_files()?;
-        
+        let mut content_files = self.collect_content_files()?;
+        self.link_translations(&mut content_files);
+
         println!("📝 Rendering {} content files...", content_files.len());
-        for content in &content_files {
-            self.render_content_file(content).await?;
-        }
+        content_files
+            .par_iter()
+            .try_for_each(|content| self.render_content_file(content))?;
 
         println!("📋 Generating index pages...");
         self.generate_index_pages(&content_files).await?;
@@ -52,11 +71,147 @@ impl SiteGenerator {
         self.copy_static_assets()?;
 
         println!("🎨 Creating special files...");
-        self.create_special_files().await?;
+        self.create_special_files(&content_files).await?;
+
+        if !self.config.search_languages().is_empty() {
+            println!("🔍 Building search index...");
+            self.build_search_indexes(&content_files)?;
+        }
+
+        if !self.config.taxonomies().is_empty() {
+            println!("🏷️  Generating taxonomy pages...");
+            self.generate_taxonomies(&content_files).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders one `taxonomy_list.html` index and, per term, one or more
+    /// `taxonomy_single.html` pages (paginated when `paginate_by` is set) for
+    /// every configured taxonomy in every language.
+    async fn generate_taxonomies(&self, content_files: &[ContentFile]) -> Result<()> {
+        use tera::Context;
+
+        let site_url = self.site_url();
+
+        for taxonomy in self.config.taxonomies() {
+            for lang in self.config.get_languages() {
+                let terms = crate::taxonomy::collect_terms(content_files, &taxonomy.name, &lang, site_url);
+                if terms.is_empty() {
+                    continue;
+                }
+
+                let prefix = self.lang_prefix(&lang);
+
+                let mut list_context = Context::new();
+                list_context.insert("site", &self.config);
+                list_context.insert("lang", &lang);
+                list_context.insert("taxonomy_name", &taxonomy.name);
+                list_context.insert("terms", &terms);
+
+                let list_template = "taxonomy_list.html";
+                if let Ok(rendered) = self.template_engine.render_page(list_template, &list_context) {
+                    let output_path = self
+                        .output_dir
+                        .join(&prefix)
+                        .join(&taxonomy.name)
+                        .join("index.html");
+                    ensure_dir_exists(&output_path)?;
+                    fs::write(output_path, self.maybe_minify(rendered))?;
+                }
+
+                for term in &terms {
+                    let base_url = format!("/{}{}/{}/", prefix, taxonomy.name, term.slug);
+                    let pagers = match taxonomy.paginate_by {
+                        Some(per_page) => crate::taxonomy::paginate(&term.pages, per_page, &base_url),
+                        None => crate::taxonomy::paginate(&term.pages, term.pages.len().max(1), &base_url),
+                    };
+
+                    for paginator in &pagers {
+                        let mut context = Context::new();
+                        context.insert("site", &self.config);
+                        context.insert("lang", &lang);
+                        context.insert("taxonomy_name", &taxonomy.name);
+                        context.insert("term", &term.name);
+                        context.insert("term_slug", &term.slug);
+                        context.insert("paginator", paginator);
+
+                        let rendered = match self.template_engine.render_page("taxonomy_single.html", &context) {
+                            Ok(r) => r,
+                            Err(_) => continue,
+                        };
+
+                        let mut output_path = self
+                            .output_dir
+                            .join(&prefix)
+                            .join(&taxonomy.name)
+                            .join(&term.slug);
+                        if paginator.current_index > 0 {
+                            output_path = output_path.join("page").join((paginator.current_index + 1).to_string());
+                        }
+                        output_path.push("index.html");
+
+                        ensure_dir_exists(&output_path)?;
+                        fs::write(output_path, self.maybe_minify(rendered))?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Emits `/search_index.{lang}.json` for every language with `search:
+    /// true` in its `languages` entry.
+    fn build_search_indexes(&self, content_files: &[ContentFile]) -> Result<()> {
+        let site_url = self.site_url();
+
+        for lang in self.config.search_languages() {
+            let index = crate::search::build_index(content_files, &lang, site_url);
+            let json = serde_json::to_string(&index)?;
+            let output_path = self.output_dir.join(format!("search_index.{}.json", lang));
+            fs::write(output_path, json)?;
+        }
 
         Ok(())
     }
 
+    /// Groups `content_files` by `canonical` path so files sharing a base
+    /// name across languages are recognized as translations of one another,
+    /// then gives each page a `translations` list covering its whole group,
+    /// the page itself included.
+    fn link_translations(&self, content_files: &mut [ContentFile]) {
+        use std::collections::HashMap;
+
+        let site_url = self.site_url();
+        let mut groups: HashMap<std::path::PathBuf, Vec<usize>> = HashMap::new();
+        for (i, content) in content_files.iter().enumerate() {
+            groups.entry(content.canonical.clone()).or_default().push(i);
+        }
+
+        for indices in groups.values() {
+            if indices.len() < 2 {
+                continue;
+            }
+
+            let translations: Vec<crate::content::Translation> = indices
+                .iter()
+                .map(|&i| {
+                    let content = &content_files[i];
+                    crate::content::Translation {
+                        lang: content.language.clone(),
+                        permalink: format!("{}{}", site_url, content.get_output_path(site_url)),
+                        title: content.front_matter.title.clone().unwrap_or_default(),
+                    }
+                })
+                .collect();
+
+            for &i in indices {
+                content_files[i].translations = translations.clone();
+            }
+        }
+    }
+
     fn collect_content_files(&self) -> Result<Vec<ContentFile>> {
         let mut content_files = Vec::new();
         let excludes = self.config.exclude.clone().unwrap_or_default();
@@ -69,7 +224,7 @@ impl SiteGenerator {
             let path = entry.path();
 
             if path.is_file() && (is_markdown_file(path) || path.extension().map(|e| e == "html").unwrap_or(false)) {
-                if let Ok(content) = ContentFile::from_path(path, &self.source_dir) {
+                if let Ok(content) = ContentFile::from_path(path, &self.source_dir, &self.config.get_languages(), &self.config.get_default_lang(), &self.highlighter) {
                     content_files.push(content);
                 }
             }
@@ -78,12 +233,15 @@ impl SiteGenerator {
         Ok(content_files)
     }
 
-    async fn render_content_file(&self, content: &ContentFile) -> Result<()> {
+    /// Renders a single content file to disk. Runs on rayon's thread pool via
+    /// `build`'s parallel iterator, so this must stay synchronous and avoid any
+    /// shared mutable state beyond what `fs::create_dir_all` already handles safely.
+    fn render_content_file(&self, content: &ContentFile) -> Result<()> {
         let output_path = self.output_dir.join(content.get_file_path());
         ensure_dir_exists(&output_path)?;
 
         let rendered = self.template_engine.render_content(content, &self.config)?;
-        fs::write(output_path, rendered)?;
+        fs::write(output_path, self.maybe_minify(rendered))?;
 
         Ok(())
     }
@@ -129,49 +287,32 @@ impl SiteGenerator {
         // Note: We don't need to insert lessons here as they're handled by templates
 
         // Read index template from source
-        let index_template_path = match lang {
-            "pt" => self.source_dir.join("pt").join("index.md"),
-            "es" => self.source_dir.join("es").join("index.md"),
-            _ => self.source_dir.join("index.md"),
-        };
+        let prefix = self.lang_prefix(lang);
+        let index_template_path = self.source_dir.join(&prefix).join("index.md");
 
         if index_template_path.exists() {
-            let index_content = ContentFile::from_path(&index_template_path, &self.source_dir)?;
+            let index_content = ContentFile::from_path(&index_template_path, &self.source_dir, &self.config.get_languages(), &self.config.get_default_lang(), &self.highlighter)?;
             let rendered = self.template_engine.render_content(&index_content, &self.config)?;
-            
-            let output_path = match lang {
-                "pt" => self.output_dir.join("pt").join("index.html"),
-                "es" => self.output_dir.join("es").join("index.html"),
-                _ => self.output_dir.join("index.html"),
-            };
+            let output_path = self.output_dir.join(&prefix).join("index.html");
 
             ensure_dir_exists(&output_path)?;
-            fs::write(output_path, rendered)?;
+            fs::write(output_path, self.maybe_minify(rendered))?;
         }
 
         Ok(())
     }
 
     async fn generate_lessons_index(&self, lang: &str, _content_files: &[ContentFile]) -> Result<()> {
-        
-        let lessons_template_path = match lang {
-            "pt" => self.source_dir.join("pt").join("lessons").join("index.md"),
-            "es" => self.source_dir.join("es").join("lessons").join("index.md"),
-            _ => self.source_dir.join("lessons").join("index.md"),
-        };
+        let prefix = self.lang_prefix(lang);
+        let lessons_template_path = self.source_dir.join(&prefix).join("lessons").join("index.md");
 
         if lessons_template_path.exists() {
-            let lessons_content = ContentFile::from_path(&lessons_template_path, &self.source_dir)?;
+            let lessons_content = ContentFile::from_path(&lessons_template_path, &self.source_dir, &self.config.get_languages(), &self.config.get_default_lang(), &self.highlighter)?;
             let rendered = self.template_engine.render_content(&lessons_content, &self.config)?;
-            
-            let output_path = match lang {
-                "pt" => self.output_dir.join("pt").join("lessons").join("index.html"),
-                "es" => self.output_dir.join("es").join("lessons").join("index.html"),
-                _ => self.output_dir.join("lessons").join("index.html"),
-            };
+            let output_path = self.output_dir.join(&prefix).join("lessons").join("index.html");
 
             ensure_dir_exists(&output_path)?;
-            fs::write(output_path, rendered)?;
+            fs::write(output_path, self.maybe_minify(rendered))?;
         }
 
         Ok(())
@@ -183,14 +324,24 @@ impl SiteGenerator {
         if assets_src.exists() {
             let assets_dst = self.output_dir.join("assets");
             copy_dir_recursive(&assets_src, &assets_dst)?;
+            self.compile_sass(&assets_src, &assets_dst)?;
+        }
+
+        // In "css" highlighter mode, syntect emits class names instead of
+        // inline styles, so the matching stylesheet has to ship separately.
+        if let Some(css) = self.highlighter.css_theme() {
+            let assets_dst = self.output_dir.join("assets");
+            ensure_dir_exists(&assets_dst.join("syntax.css"))?;
+            fs::write(assets_dst.join("syntax.css"), css)?;
         }
 
         // Copy special files
+        // sitemap.xml is generated from the collected content files in
+        // `create_special_files` rather than copied verbatim.
         let special_files = [
             "manifest.json",
             "sw.js",
             "robots.txt",
-            "sitemap.xml",
             "offline.html",
             ".nojekyll",
         ];
@@ -206,43 +357,186 @@ impl SiteGenerator {
 
         // Copy about pages
         for lang in self.config.get_languages() {
-            let about_src = match lang.as_str() {
-                "pt" => self.source_dir.join("pt").join("about.md"),
-                "es" => self.source_dir.join("es").join("about.md"),
-                _ => self.source_dir.join("about.md"),
-            };
+            let prefix = self.lang_prefix(&lang);
+            let about_src = self.source_dir.join(&prefix).join("about.md");
 
             if about_src.exists() {
-                let about_content = ContentFile::from_path(&about_src, &self.source_dir)?;
+                let about_content = ContentFile::from_path(&about_src, &self.source_dir, &self.config.get_languages(), &self.config.get_default_lang(), &self.highlighter)?;
                 let rendered = self.template_engine.render_content(&about_content, &self.config)?;
-                
-                let output_path = match lang.as_str() {
-                    "pt" => self.output_dir.join("pt").join("about").join("index.html"),
-                    "es" => self.output_dir.join("es").join("about").join("index.html"),
-                    _ => self.output_dir.join("about").join("index.html"),
-                };
+                let output_path = self.output_dir.join(&prefix).join("about").join("index.html");
 
                 ensure_dir_exists(&output_path)?;
-                fs::write(output_path, rendered)?;
+                fs::write(output_path, self.maybe_minify(rendered))?;
             }
         }
 
         Ok(())
     }
 
-    async fn create_special_files(&self) -> Result<()> {
+    /// Compiles top-level `*.scss`/`*.sass` files under `assets/` to sibling
+    /// `.css` files, skipping Sass partials (files prefixed with `_`, which
+    /// only exist to be `@import`ed) since they have nothing to emit on their
+    /// own. The raw source that `copy_dir_recursive` already copied verbatim
+    /// is removed from the output so only the compiled CSS ships.
+    fn compile_sass(&self, assets_src: &std::path::Path, assets_dst: &std::path::Path) -> Result<()> {
+        if !self.config.sass_enabled() {
+            return Ok(());
+        }
+
+        let style = if self.config.sass_compressed() {
+            grass::OutputStyle::Compressed
+        } else {
+            grass::OutputStyle::Expanded
+        };
+
+        for entry in fs::read_dir(assets_src)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            let is_sass = path
+                .extension()
+                .map(|e| e == "scss" || e == "sass")
+                .unwrap_or(false);
+
+            if !path.is_file() || !is_sass {
+                continue;
+            }
+
+            let copied_source = assets_dst.join(entry.file_name());
+
+            let is_partial = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with('_'))
+                .unwrap_or(false);
+
+            if is_partial {
+                // Partials exist only to be `@use`d by other Sass files, not
+                // served directly — `copy_dir_recursive` already shipped a
+                // verbatim copy before this function ran, so remove it.
+                if copied_source.exists() {
+                    fs::remove_file(copied_source)?;
+                }
+                continue;
+            }
+
+            let options = grass::Options::default().style(style);
+            let css = grass::from_path(&path, &options)
+                .map_err(|e| anyhow::anyhow!("Failed to compile {}: {}", path.display(), e))?;
+
+            let css_name = format!(
+                "{}.css",
+                path.file_stem().and_then(|s| s.to_str()).unwrap_or("style")
+            );
+            fs::write(assets_dst.join(css_name), css)?;
+
+            if copied_source.exists() {
+                fs::remove_file(copied_source)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn create_special_files(&self, content_files: &[ContentFile]) -> Result<()> {
         // Create .nojekyll file to disable Jekyll on GitHub Pages with timestamp for cache busting
         let nojekyll_path = self.output_dir.join(".nojekyll");
         let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
         fs::write(nojekyll_path, format!("# Generated by Rust Static Site Generator\n# Build time: {}\n", timestamp))?;
 
-        // Generate feed.xml (basic implementation)
-        self.generate_feed().await?;
+        self.generate_sitemap(content_files)?;
+        self.generate_feeds(content_files).await?;
+
+        Ok(())
+    }
+
+    fn site_url(&self) -> &str {
+        self.config.url.as_deref().unwrap_or("https://incrediblerust.github.io")
+    }
+
+    /// Builds a `{ permalink, date }` entry per content file, the same shape
+    /// Zola uses for its sitemap, so the XML writers below can stay dumb.
+    fn sitemap_entries(&self, content_files: &[ContentFile]) -> Vec<SitemapEntry> {
+        let site_url = self.site_url();
+        content_files
+            .iter()
+            .map(|content| SitemapEntry {
+                permalink: format!("{}{}", site_url, content.get_output_path(site_url)),
+                date: content.date(),
+            })
+            .collect()
+    }
+
+    fn generate_sitemap(&self, content_files: &[ContentFile]) -> Result<()> {
+        let entries = self.sitemap_entries(content_files);
+
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        xml.push_str("\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+
+        for entry in &entries {
+            xml.push_str("  <url>\n");
+            xml.push_str(&format!("    <loc>{}</loc>\n", entry.permalink));
+            if let Some(date) = &entry.date {
+                xml.push_str(&format!("    <lastmod>{}</lastmod>\n", date));
+            }
+            xml.push_str("  </url>\n");
+        }
+
+        xml.push_str("</urlset>\n");
+
+        fs::write(self.output_dir.join("sitemap.xml"), xml)?;
+        Ok(())
+    }
+
+    /// The number of most-recent entries kept in a generated feed.
+    const FEED_ITEM_LIMIT: usize = 20;
+
+    /// Renders `/{lang-prefix}/atom.xml` for every language returned by
+    /// `rss_languages`: the default language unless its entry sets `rss:
+    /// false`, plus every other language whose entry sets `rss: true`.
+    async fn generate_feeds(&self, content_files: &[ContentFile]) -> Result<()> {
+        for lang in self.config.rss_languages() {
+            self.generate_feed(content_files, &lang)?;
+        }
 
         Ok(())
     }
 
-    async fn generate_feed(&self) -> Result<()> {
+    fn generate_feed(&self, content_files: &[ContentFile], lang: &str) -> Result<()> {
+        let site_url = self.site_url();
+
+        let mut entries: Vec<&ContentFile> = content_files
+            .iter()
+            .filter(|c| c.language == lang)
+            .collect();
+        entries.sort_by(|a, b| b.date().cmp(&a.date()));
+        entries.truncate(Self::FEED_ITEM_LIMIT);
+
+        let mut items = String::new();
+        for content in &entries {
+            let permalink = format!("{}{}", site_url, content.get_output_path(site_url));
+            let title = content
+                .front_matter
+                .title
+                .as_deref()
+                .unwrap_or("Untitled");
+            let pub_date = rfc822_date(content.date().as_deref());
+
+            items.push_str("    <item>\n");
+            items.push_str(&format!("      <title>{}</title>\n", html_escape::encode_text(title)));
+            items.push_str(&format!("      <link>{}</link>\n", permalink));
+            items.push_str(&format!("      <guid>{}</guid>\n", permalink));
+            items.push_str(&format!("      <pubDate>{}</pubDate>\n", pub_date));
+            items.push_str(&format!(
+                "      <description><![CDATA[{}]]></description>\n",
+                content.html_content
+            ));
+            items.push_str("    </item>\n");
+        }
+
+        let prefix = self.lang_prefix(lang);
+        let feed_url = format!("{}/{}atom.xml", site_url, prefix);
+
         let feed_content = format!(
             r#"<?xml version="1.0" encoding="UTF-8"?>
 <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
@@ -250,23 +544,71 @@ impl SiteGenerator {
     <title>{}</title>
     <description>{}</description>
     <link>{}</link>
-    <atom:link href="{}/feed.xml" rel="self" type="application/rss+xml"/>
+    <atom:link href="{}" rel="self" type="application/rss+xml"/>
     <pubDate>{}</pubDate>
     <lastBuildDate>{}</lastBuildDate>
     <generator>Incredible Rust Generator</generator>
-  </channel>
+{}  </channel>
 </rss>"#,
             self.config.title.as_deref().unwrap_or("The Incredible Rust"),
             self.config.description.as_deref().unwrap_or("Learn Rust Programming"),
-            self.config.url.as_deref().unwrap_or("https://incrediblerust.github.io"),
-            self.config.url.as_deref().unwrap_or("https://incrediblerust.github.io"),
+            site_url,
+            feed_url,
             chrono::Utc::now().format("%a, %d %b %Y %H:%M:%S %z"),
             chrono::Utc::now().format("%a, %d %b %Y %H:%M:%S %z"),
+            items,
         );
 
-        let feed_path = self.output_dir.join("feed.xml");
-        fs::write(feed_path, feed_content)?;
+        let feed_dir = self.output_dir.join(prefix.trim_end_matches('/'));
+        fs::create_dir_all(&feed_dir)?;
+        fs::write(feed_dir.join("atom.xml"), feed_content)?;
 
         Ok(())
     }
+
+    /// Minifies rendered HTML when `minify_html` is enabled, using a
+    /// spec-aware minifier that leaves `<pre>`, `<code>`, `<textarea>`,
+    /// `<script>`, and `<style>` content untouched so code samples in the
+    /// lessons stay byte-for-byte intact.
+    fn maybe_minify(&self, html: String) -> String {
+        if !self.config.minify_html() {
+            return html;
+        }
+
+        let cfg = minify_html::Cfg::new();
+        let minified = minify_html::minify(html.as_bytes(), &cfg);
+        String::from_utf8(minified).unwrap_or(html)
+    }
+
+    /// The output-path prefix for a language: the default language lives at
+    /// the site root, every other configured language nests under its code.
+    /// Centralizes what used to be `match lang { "pt" => ..., "es" => ... }`
+    /// branches repeated across the index/lessons/about generators, so
+    /// adding a language is purely a config change.
+    fn lang_prefix(&self, lang: &str) -> String {
+        if lang == self.config.get_default_lang() {
+            String::new()
+        } else {
+            format!("{}/", lang)
+        }
+    }
+}
+
+/// A single `<url>` entry, mirroring Zola's `SitemapEntry { permalink, date }`.
+struct SitemapEntry {
+    permalink: String,
+    date: Option<String>,
+}
+
+/// Formats a front-matter date as RFC-822 for an RSS `<pubDate>`, the format
+/// the spec requires. Front matter only carries a bare `YYYY-MM-DD`, so the
+/// time defaults to midnight UTC; falls back to now when `date` is absent or
+/// unparseable.
+fn rfc822_date(date: Option<&str>) -> String {
+    const RFC822: &str = "%a, %d %b %Y %H:%M:%S %z";
+
+    date.and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|naive| naive.and_utc().format(RFC822).to_string())
+        .unwrap_or_else(|| chrono::Utc::now().format(RFC822).to_string())
 }
\ No newline at end of file
@@ -0,0 +1,99 @@
+use anyhow::{Context, Result};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Style, Theme, ThemeSet};
+use syntect::html::{
+    css_for_theme_with_class_style, styled_line_to_highlighted_html, ClassStyle,
+    ClassedHTMLGenerator, IncludeBackground,
+};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+const DEFAULT_THEME: &str = "InspiredGitHub";
+
+#[derive(Clone)]
+enum Mode {
+    /// Inline `style="..."` spans, colored directly from the theme.
+    Inline(Theme),
+    /// `<span class="...">` output plus a separately written stylesheet, so
+    /// users can swap color schemes without rebuilding.
+    Css { theme: Theme },
+}
+
+/// Highlights fenced code blocks with syntect, configured via `_config.yml`'s
+/// `syntect_theme` key: a `ThemeSet` theme name, or the literal `"css"` for
+/// class-based output. The theme is resolved once at startup so a typo in
+/// `_config.yml` fails the build immediately instead of silently falling
+/// back to plain text.
+#[derive(Clone)]
+pub struct SyntaxHighlighter {
+    syntax_set: SyntaxSet,
+    mode: Mode,
+}
+
+impl SyntaxHighlighter {
+    pub fn new(theme_name: Option<&str>) -> Result<Self> {
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let theme_set = ThemeSet::load_defaults();
+
+        let resolve = |name: &str| -> Result<Theme> {
+            theme_set
+                .themes
+                .get(name)
+                .cloned()
+                .with_context(|| format!("Unknown syntax highlighting theme '{}'", name))
+        };
+
+        let mode = match theme_name {
+            Some("css") => Mode::Css { theme: resolve(DEFAULT_THEME)? },
+            Some(name) => Mode::Inline(resolve(name)?),
+            None => Mode::Inline(resolve(DEFAULT_THEME)?),
+        };
+
+        Ok(SyntaxHighlighter { syntax_set, mode })
+    }
+
+    /// Highlights `code` (the concatenated text of one fenced code block)
+    /// using `lang` as the syntax token, falling back to plain text when the
+    /// language is unrecognized.
+    pub fn highlight(&self, code: &str, lang: &str) -> String {
+        let syntax = self
+            .syntax_set
+            .find_syntax_by_token(lang)
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+
+        let body = match &self.mode {
+            Mode::Inline(theme) => {
+                let mut highlighter = HighlightLines::new(syntax, theme);
+                let mut out = String::new();
+                for line in LinesWithEndings::from(code) {
+                    if let Ok(ranges) = highlighter.highlight_line(line, &self.syntax_set) {
+                        out.push_str(
+                            &styled_line_to_highlighted_html(&ranges[..], IncludeBackground::Yes)
+                                .unwrap_or_default(),
+                        );
+                    }
+                }
+                out
+            }
+            Mode::Css { .. } => {
+                let mut generator =
+                    ClassedHTMLGenerator::new_with_class_style(syntax, &self.syntax_set, ClassStyle::Spaced);
+                for line in LinesWithEndings::from(code) {
+                    let _ = generator.parse_html_for_line_which_includes_newline(line);
+                }
+                generator.finalize()
+            }
+        };
+
+        format!("<pre class=\"highlight\"><code>{}</code></pre>", body)
+    }
+
+    /// The stylesheet to write alongside the output in `"css"` mode; `None`
+    /// when running in inline-style mode, which needs no separate file.
+    pub fn css_theme(&self) -> Option<String> {
+        match &self.mode {
+            Mode::Css { theme } => css_for_theme_with_class_style(theme, ClassStyle::Spaced).ok(),
+            Mode::Inline(_) => None,
+        }
+    }
+}
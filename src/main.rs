@@ -6,6 +6,10 @@ use tokio::main;
 mod config;
 mod content;
 mod generator;
+mod highlight;
+mod search;
+mod serve;
+mod taxonomy;
 mod templates;
 mod utils;
 
@@ -41,6 +45,25 @@ async fn main() -> Result<()> {
                 .help("Configuration file")
                 .default_value("_config.yml"),
         )
+        .subcommand(
+            Command::new("serve")
+                .about("Build the site and serve it locally with live-reload")
+                .arg(
+                    Arg::new("host")
+                        .long("host")
+                        .value_name("HOST")
+                        .help("Interface to bind")
+                        .default_value("127.0.0.1"),
+                )
+                .arg(
+                    Arg::new("port")
+                        .short('p')
+                        .long("port")
+                        .value_name("PORT")
+                        .help("Port to bind (falls back to a free one if taken)")
+                        .default_value("1111"),
+                ),
+        )
         .get_matches();
 
     let source = matches.get_one::<String>("source").unwrap();
@@ -58,6 +81,19 @@ async fn main() -> Result<()> {
 
     // Create generator and build site
     let generator = SiteGenerator::new(source, destination, config)?;
+
+    if let Some(serve_matches) = matches.subcommand_matches("serve") {
+        let host = serve_matches.get_one::<String>("host").unwrap();
+        let port: u16 = serve_matches
+            .get_one::<String>("port")
+            .unwrap()
+            .parse()
+            .with_context(|| "Invalid --port value")?;
+
+        serve::serve(generator, host, port).await?;
+        return Ok(());
+    }
+
     generator.build().await?;
 
     println!("✅ Site generated successfully!");
@@ -0,0 +1,57 @@
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::content::ContentFile;
+use crate::utils::strip_html_tags;
+
+/// One searchable page, mirroring Zola's search component: enough to render
+/// a result (title, url) plus the plain-text body for local term matching.
+#[derive(Debug, Serialize)]
+pub struct SearchDocument {
+    pub id: usize,
+    pub title: String,
+    pub url: String,
+    pub body: String,
+}
+
+/// A documents array plus a token -> document-id inverted map, so a small JS
+/// snippet can do prefix/fuzzy search fully offline without re-tokenizing
+/// every document on each keystroke.
+#[derive(Debug, Serialize)]
+pub struct SearchIndex {
+    pub documents: Vec<SearchDocument>,
+    pub index: HashMap<String, Vec<usize>>,
+}
+
+/// Builds a search index over every `ContentFile` in `lang`, using
+/// `site_url` to resolve each document's permalink.
+pub fn build_index(content_files: &[ContentFile], lang: &str, site_url: &str) -> SearchIndex {
+    let mut documents = Vec::new();
+    let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+
+    for content in content_files.iter().filter(|c| c.language == lang) {
+        let id = documents.len();
+        let title = content.front_matter.title.clone().unwrap_or_default();
+        let url = format!("{}{}", site_url, content.get_output_path(site_url));
+        let body = strip_html_tags(&content.html_content);
+
+        for token in tokenize(&title).chain(tokenize(&body)) {
+            let ids = index.entry(token).or_default();
+            if ids.last() != Some(&id) {
+                ids.push(id);
+            }
+        }
+
+        documents.push(SearchDocument { id, title, url, body });
+    }
+
+    SearchIndex { documents, index }
+}
+
+/// Lowercases and splits on non-alphanumeric boundaries; callers dedupe
+/// per-document via the `ids.last() != Some(&id)` check above.
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+}
@@ -0,0 +1,149 @@
+use anyhow::Result;
+use notify::{RecursiveMode, Watcher};
+use std::net::TcpListener;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use warp::Filter;
+
+use crate::generator::SiteGenerator;
+use crate::utils::should_exclude;
+
+/// Injected into served HTML so browsers pick up rebuilds without a manual refresh.
+const LIVERELOAD_SNIPPET: &str = r#"<script>
+(function () {
+  var last = null;
+  setInterval(function () {
+    fetch('/__livereload').then(function (r) { return r.text(); }).then(function (v) {
+      if (last !== null && v !== last) { location.reload(); }
+      last = v;
+    }).catch(function () {});
+  }, 300);
+})();
+</script>"#;
+
+/// Builds the site once, then serves `output_dir` over HTTP while watching
+/// `source_dir` for changes and rebuilding the whole site (not only the
+/// changed pages) after each burst of changes, pushing a reload to connected
+/// browsers through a small polling endpoint.
+pub async fn serve(generator: SiteGenerator, host: &str, requested_port: u16) -> Result<()> {
+    println!("🔨 Building site...");
+    generator.build().await?;
+
+    let port = find_free_port(host, requested_port)?;
+    let build_version = Arc::new(AtomicU64::new(0));
+
+    let watch_generator = generator.clone();
+    let watch_version = build_version.clone();
+    std::thread::spawn(move || watch_and_rebuild(watch_generator, watch_version));
+
+    let version_route = {
+        let build_version = build_version.clone();
+        warp::path("__livereload").map(move || build_version.load(Ordering::SeqCst).to_string())
+    };
+
+    let output_dir = generator.output_dir().to_path_buf();
+    let static_files = warp::fs::dir(output_dir).map(inject_livereload_snippet);
+
+    println!("📡 Serving {} on http://{}:{}", generator.output_dir().display(), host, port);
+    let addr: std::net::IpAddr = host.parse()?;
+    warp::serve(version_route.or(static_files)).run((addr, port)).await;
+
+    Ok(())
+}
+
+/// Zola-style port fallback: try the requested port first, then let the OS
+/// hand back any free one so `serve` never fails just because a previous
+/// run's listener is still lingering.
+fn find_free_port(host: &str, requested: u16) -> Result<u16> {
+    if TcpListener::bind((host, requested)).is_ok() {
+        return Ok(requested);
+    }
+    let listener = TcpListener::bind((host, 0))?;
+    Ok(listener.local_addr()?.port())
+}
+
+/// Watches `source_dir` for filesystem events, debounces bursts of edits
+/// (editors often emit several writes per save) for ~200ms, then re-runs the
+/// full `build` and bumps `build_version` so polling clients reload. Runs on
+/// its own OS thread so it never blocks the warp server.
+fn watch_and_rebuild(generator: SiteGenerator, build_version: Arc<AtomicU64>) {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(w) => w,
+        Err(e) => {
+            eprintln!("⚠️  Could not start file watcher: {}", e);
+            return;
+        }
+    };
+
+    if let Err(e) = watcher.watch(generator.source_dir(), RecursiveMode::Recursive) {
+        eprintln!("⚠️  Could not watch {}: {}", generator.source_dir().display(), e);
+        return;
+    }
+
+    let excludes = generator.config().exclude.clone().unwrap_or_default();
+    // `source_dir` is watched recursively and contains `output_dir` (by
+    // default `.` and `./_site`), so every rebuild's writes fire their own
+    // events. Canonicalize once and filter those out below, or every rebuild
+    // triggers another rebuild forever.
+    let output_dir = generator
+        .output_dir()
+        .canonicalize()
+        .unwrap_or_else(|_| generator.output_dir().to_path_buf());
+    let rt = tokio::runtime::Runtime::new().expect("failed to start watcher runtime");
+    let mut pending = false;
+
+    loop {
+        match rx.recv_timeout(Duration::from_millis(200)) {
+            Ok(Ok(event)) if is_relevant(&event, &excludes, &output_dir) => pending = true,
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => eprintln!("⚠️  Watch error: {}", e),
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                if pending {
+                    pending = false;
+                    println!("🔄 Change detected, rebuilding...");
+                    match rt.block_on(generator.build()) {
+                        Ok(()) => {
+                            build_version.fetch_add(1, Ordering::SeqCst);
+                        }
+                        Err(e) => eprintln!("❌ Rebuild failed: {}", e),
+                    }
+                }
+            }
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+fn is_relevant(event: &notify::Event, excludes: &[String], output_dir: &Path) -> bool {
+    event
+        .paths
+        .iter()
+        .any(|p| !should_exclude(p, excludes) && !is_within(p, output_dir))
+}
+
+/// Whether `path` lives inside `dir`, comparing canonicalized forms since
+/// `notify` reports absolute paths while `output_dir` may still be relative.
+fn is_within(path: &Path, dir: &Path) -> bool {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    path.starts_with(dir)
+}
+
+fn inject_livereload_snippet(reply: warp::filters::fs::File) -> impl warp::Reply {
+    if reply.path().extension().and_then(|e| e.to_str()) == Some("html") {
+        if let Ok(body) = std::fs::read_to_string(reply.path()) {
+            let with_snippet = match body.rfind("</body>") {
+                Some(idx) => {
+                    let mut out = body.clone();
+                    out.insert_str(idx, LIVERELOAD_SNIPPET);
+                    out
+                }
+                None => format!("{}{}", body, LIVERELOAD_SNIPPET),
+            };
+            return warp::reply::html(with_snippet).into_response();
+        }
+    }
+    reply.into_response()
+}
@@ -0,0 +1,116 @@
+//! Taxonomy aggregation: groups `ContentFile`s by a front-matter field
+//! (`difficulty`, `tags`, ...) into per-language term pages, with pagination
+//! for long terms. This is the full subsystem; it shipped in one piece rather
+//! than incrementally, so later backlog items asking for the same feature
+//! (binning + per-language pages + pagination) land here too instead of
+//! adding new code.
+
+use serde::Serialize;
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+
+use crate::content::ContentFile;
+use crate::utils::slugify;
+
+/// A page as seen from a taxonomy term listing: just enough to link to it.
+#[derive(Debug, Clone, Serialize)]
+pub struct TermPage {
+    pub title: String,
+    pub url: String,
+}
+
+/// One term within a taxonomy (e.g. the `async` term of the `tags` taxonomy),
+/// with every page that declared it in front matter. The taxonomy subsystem
+/// itself (binning, per-language term pages, pagination) lives in
+/// `collect_terms`/`paginate` below; `count` just saves `taxonomy_list.html`
+/// from computing `pages | length` per term.
+#[derive(Debug, Clone, Serialize)]
+pub struct Term {
+    pub name: String,
+    pub slug: String,
+    pub count: usize,
+    pub pages: Vec<TermPage>,
+}
+
+/// A single slice of a paginated term page, mirroring the `paginator`
+/// context other static site generators expose to templates.
+#[derive(Debug, Clone, Serialize)]
+pub struct Paginator {
+    pub pages: Vec<TermPage>,
+    pub current_index: usize,
+    pub number_pagers: usize,
+    pub previous: Option<String>,
+    pub next: Option<String>,
+}
+
+/// Groups `content_files` in `lang` by every value they declare for the
+/// `taxonomy_name` front-matter field. A single-string field (`difficulty:
+/// beginner`) contributes one term; a list field (`tags: [async, io]`) fans
+/// out to one term per entry.
+pub fn collect_terms(content_files: &[ContentFile], taxonomy_name: &str, lang: &str, site_url: &str) -> Vec<Term> {
+    let mut terms: BTreeMap<String, Vec<TermPage>> = BTreeMap::new();
+
+    for content in content_files.iter().filter(|c| c.language == lang) {
+        for term_name in term_values(content, taxonomy_name) {
+            let page = TermPage {
+                title: content.front_matter.title.clone().unwrap_or_default(),
+                url: format!("{}{}", site_url, content.get_output_path(site_url)),
+            };
+            terms.entry(term_name).or_default().push(page);
+        }
+    }
+
+    terms
+        .into_iter()
+        .map(|(name, pages)| Term {
+            slug: slugify(&name),
+            name,
+            count: pages.len(),
+            pages,
+        })
+        .collect()
+}
+
+fn term_values(content: &ContentFile, taxonomy_name: &str) -> Vec<String> {
+    match taxonomy_name {
+        "difficulty" => content.front_matter.difficulty.clone().into_iter().collect(),
+        _ => match content.front_matter.extra.get(taxonomy_name) {
+            Some(Value::String(s)) => vec![s.clone()],
+            Some(Value::Sequence(seq)) => seq
+                .iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect(),
+            _ => Vec::new(),
+        },
+    }
+}
+
+/// Splits `pages` into `paginate_by`-sized pagers, building the `previous`/
+/// `next` links a `taxonomy_single.html` template expects. `base_url` is the
+/// term's own first-page URL (e.g. `/tags/async/`).
+pub fn paginate(pages: &[TermPage], paginate_by: usize, base_url: &str) -> Vec<Paginator> {
+    let chunks: Vec<&[TermPage]> = pages.chunks(paginate_by.max(1)).collect();
+    let number_pagers = chunks.len();
+
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| {
+            let page_url = |index: usize| {
+                if index == 0 {
+                    base_url.to_string()
+                } else {
+                    format!("{}page/{}/", base_url, index + 1)
+                }
+            };
+
+            Paginator {
+                pages: chunk.to_vec(),
+                current_index: i,
+                number_pagers,
+                previous: if i == 0 { None } else { Some(page_url(i - 1)) },
+                next: if i + 1 == number_pagers { None } else { Some(page_url(i + 1)) },
+            }
+        })
+        .collect()
+}
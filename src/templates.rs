@@ -6,6 +6,7 @@ use tera::{Context, Tera};
 
 use crate::content::ContentFile;
 
+#[derive(Clone)]
 pub struct TemplateEngine {
     tera: Tera,
     data: HashMap<String, Value>,
@@ -34,10 +34,14 @@ pub fn ensure_dir_exists(path: &Path) -> Result<()> {
 pub fn slug_from_path(path: &Path) -> String {
     path.file_stem()
         .and_then(|s| s.to_str())
-        .map(|s| slug::slugify(s))
+        .map(slugify)
         .unwrap_or_else(|| "untitled".to_string())
 }
 
+pub fn slugify(s: &str) -> String {
+    slug::slugify(s)
+}
+
 pub fn is_markdown_file(path: &Path) -> bool {
     if let Some(extension) = path.extension() {
         extension == "md" || extension == "markdown"
@@ -54,6 +58,23 @@ pub fn is_html_file(path: &Path) -> bool {
     }
 }
 
+/// Strips HTML tags from rendered content, collapsing whitespace left behind.
+/// Used where we need plain text from `ContentFile::html_content` (the search
+/// index) without re-rendering from markdown.
+pub fn strip_html_tags(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 pub fn should_exclude(path: &Path, excludes: &[String]) -> bool {
     let path_str = path.to_string_lossy();
     